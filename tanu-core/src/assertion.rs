@@ -50,6 +50,8 @@ pub enum Error {
 ///
 /// - On success: Publishes a success event to the test runner
 /// - On failure: Publishes a failure event and returns an `eyre::Error`
+/// - The result depends only on the condition; failing to deliver the event
+///   to reporters never turns a passing check into an error
 /// - Integrates with tanu's reporting system for colored output and detailed logs
 #[macro_export]
 macro_rules! check {
@@ -63,12 +65,12 @@ macro_rules! check {
         if !$cond {
             let __message = format!("check failed: {}{}{}", stringify!($cond), $maybe_colon, format_args!($($arg)*));
             let __check = tanu::runner::Check::error(&__message);
-            tanu::runner::publish(tanu::runner::EventBody::Check(Box::new(__check)))?;
+            tanu::runner::publish_check(__check);
             tanu::eyre::bail!(__message);
         } else {
             let __message = format!("check succeeded: {}{}{}", stringify!($cond), $maybe_colon, format_args!($($arg)*));
             let __check = tanu::runner::Check::success(&__message);
-            tanu::runner::publish(tanu::runner::EventBody::Check(Box::new(__check)))?;
+            tanu::runner::publish_check(__check);
         }
     };
 }
@@ -136,7 +138,7 @@ macro_rules! check_str_eq {
                        tanu::pretty_assertions::StrComparison::new(left_val, right_val)
                     );
                     let __check = tanu::runner::Check::error(&__message);
-                    tanu::runner::publish(tanu::runner::EventBody::Check(Box::new(__check)))?;
+                    tanu::runner::publish_check(__check);
                     Err(tanu::assertion::Error::StrEq(__message))?;
                 } else {
                     let __message = format!("check succeeded: `(left == right)`{}{}\
@@ -148,7 +150,7 @@ macro_rules! check_str_eq {
                        tanu::pretty_assertions::StrComparison::new(left_val, right_val)
                     );
                     let __check = tanu::runner::Check::success(&__message);
-                    tanu::runner::publish(tanu::runner::EventBody::Check(Box::new(__check)))?;
+                    tanu::runner::publish_check(__check);
                 }
             }
         }
@@ -212,7 +214,7 @@ macro_rules! check_eq {
                        tanu::pretty_assertions::Comparison::new(left_val, right_val)
                     );
                     let __check = tanu::runner::Check::error(&__message);
-                    tanu::runner::publish(tanu::runner::EventBody::Check(Box::new(__check)))?;
+                    tanu::runner::publish_check(__check);
                     Err(tanu::assertion::Error::Eq(__message))?;
                 } else {
                     let __message = format!("check succeeded: `(left == right)`{}{}\
@@ -224,7 +226,7 @@ macro_rules! check_eq {
                        tanu::pretty_assertions::Comparison::new(left_val, right_val)
                     );
                     let __check = tanu::runner::Check::success(&__message);
-                    tanu::runner::publish(tanu::runner::EventBody::Check(Box::new(__check)))?;
+                    tanu::runner::publish_check(__check);
                 }
             }
         }
@@ -297,7 +299,7 @@ macro_rules! check_ne {
                         left_val
                     );
                     let __check = tanu::runner::Check::error(&__message);
-                    tanu::runner::publish(tanu::runner::EventBody::Check(Box::new(__check)))?;
+                    tanu::runner::publish_check(__check);
                     Err(tanu::assertion::Error::Ne(__message))?;
                 } else {
                     let __message = format!("check succeeded: `(left != right)`{}{}\
@@ -311,7 +313,7 @@ macro_rules! check_ne {
                         left_val
                     );
                     let __check = tanu::runner::Check::success(&__message);
-                    tanu::runner::publish(tanu::runner::EventBody::Check(Box::new(__check)))?;
+                    tanu::runner::publish_check(__check);
                 }
             }
         }
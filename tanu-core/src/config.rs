@@ -69,6 +69,10 @@
 //! retry.count = 3
 //! retry.factor = 2.0
 //! test_ignore = ["slow_test", "flaky_test"]
+//! quarantine = ["users::flaky_test"]
+//!
+//! [projects.sla]
+//! "users::list" = "300ms"
 //!
 //! [[projects]]
 //! name = "production"
@@ -402,10 +406,31 @@ pub struct ProjectConfig {
     /// List of files to ignore in the project.
     #[serde(default)]
     pub test_ignore: Vec<String>,
+    /// List of known-flaky tests. They still run and are reported, but their
+    /// failures do not make the test run fail.
+    #[serde(default)]
+    pub quarantine: Vec<String>,
+    /// Maximum allowed execution time per test, keyed by full test name
+    /// (`module::test`) or unique test name (`project::module::test`).
+    #[serde(default, deserialize_with = "deserialize_sla")]
+    pub sla: HashMap<String, Duration>,
     #[serde(default)]
     pub retry: RetryConfig,
 }
 
+fn deserialize_sla<'de, D>(
+    deserializer: D,
+) -> std::result::Result<HashMap<String, Duration>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let sla = HashMap::<String, humantime_serde::Serde<Duration>>::deserialize(deserializer)?;
+    Ok(sla
+        .into_iter()
+        .map(|(name, limit)| (name, limit.into_inner()))
+        .collect())
+}
+
 impl ProjectConfig {
     pub fn get(&self, key: impl AsRef<str>) -> Result<&TomlValue> {
         let key = key.as_ref();
@@ -603,6 +628,8 @@ mod test {
         let project = &cfg.projects[0];
         assert_eq!(project.name, "default");
         assert_eq!(project.test_ignore, Vec::<String>::new());
+        assert_eq!(project.quarantine, Vec::<String>::new());
+        assert!(project.sla.is_empty());
         assert_eq!(project.retry.count, Some(0));
         assert_eq!(project.retry.factor, Some(2.0));
         assert_eq!(project.retry.jitter, Some(false));
@@ -612,6 +639,29 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn load_sla() -> eyre::Result<()> {
+        let project: ProjectConfig = toml::from_str(
+            r#"
+            name = "default"
+
+            [sla]
+            "users::list" = "300ms"
+            "default::users::get" = "1s"
+            "#,
+        )?;
+        assert_eq!(
+            project.sla.get("users::list"),
+            Some(&Duration::from_millis(300))
+        );
+        assert_eq!(
+            project.sla.get("default::users::get"),
+            Some(&Duration::from_secs(1))
+        );
+        assert!(!project.data.contains_key("sla"));
+        Ok(())
+    }
+
     #[test_case("TANU_DEFAULT_STR_KEY"; "project config")]
     #[test_case("TANU_STR_KEY"; "global config")]
     fn get_str(key: &str) -> eyre::Result<()> {
@@ -145,6 +145,18 @@ pub enum Error {
     Timeout(Duration),
     #[error("failed to deserialize http response into the specified type: {0}")]
     Deserialize(#[from] serde_json::Error),
+    #[error("failed to deserialize form-urlencoded http response into the specified type: {0}")]
+    DeserializeForm(#[from] serde_urlencoded::de::Error),
+    #[cfg(feature = "xml")]
+    #[error("failed to deserialize XML http response into the specified type: {0}")]
+    DeserializeXml(#[from] quick_xml::DeError),
+    #[cfg(feature = "msgpack")]
+    #[error("failed to deserialize MessagePack http response into the specified type: {0}")]
+    DeserializeMsgpack(#[from] rmp_serde::decode::Error),
+    #[error("cannot parse http response with content-type \"{0}\"")]
+    UnsupportedContentType(String),
+    #[error("parsing http response with content-type \"{0}\" requires the \"{1}\" feature")]
+    FeatureRequired(String, &'static str),
     #[error("{0:#}")]
     Unexpected(#[from] eyre::Error),
 }
@@ -163,6 +175,9 @@ pub struct LogRequest {
 pub struct LogResponse {
     pub headers: header::HeaderMap,
     pub body: String,
+    /// Raw response body when it is not valid UTF-8 (e.g. images), so that
+    /// exporters can encode it losslessly. `None` for text bodies.
+    pub binary_body: Option<Bytes>,
     pub status: StatusCode,
     pub duration_req: Duration,
 }
@@ -209,6 +224,8 @@ pub struct Response {
     pub status: StatusCode,
     pub text: String,
     pub url: url::Url,
+    /// Decompressed response body as received, used by parsers for binary formats.
+    body: Bytes,
     #[cfg(feature = "cookies")]
     cookies: Vec<cookie::Cookie<'static>>,
 }
@@ -264,6 +281,18 @@ impl Response {
         Ok(self.text)
     }
 
+    /// Consumes the response and returns the decompressed response body as raw bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// let bytes = response.bytes().await?;
+    /// check!(!bytes.is_empty());
+    /// ```
+    pub async fn bytes(self) -> Result<Bytes, Error> {
+        Ok(self.body)
+    }
+
     /// Consumes the response and deserializes the JSON body into the given type.
     ///
     /// # Examples
@@ -283,6 +312,67 @@ impl Response {
         Ok(serde_json::from_str(&self.text)?)
     }
 
+    /// Consumes the response and deserializes the body according to its `content-type`.
+    ///
+    /// Supported content types:
+    /// - `application/json` and `*/*+json`
+    /// - `application/x-www-form-urlencoded`
+    /// - `application/xml`, `text/xml` and `*/*+xml` (requires the `xml` feature)
+    /// - `application/msgpack`, `application/x-msgpack` and `application/vnd.msgpack`
+    ///   (requires the `msgpack` feature)
+    ///
+    /// If the feature for an XML or MessagePack content type is not enabled,
+    /// [`Error::FeatureRequired`] names the feature to enable. Any other content
+    /// type, or a missing `content-type` header, results in
+    /// [`Error::UnsupportedContentType`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// #[derive(serde::Deserialize)]
+    /// struct User { name: String, id: u64 }
+    ///
+    /// // Works whether the endpoint answers with JSON or a form-encoded body
+    /// let user: User = response.parse().await?;
+    /// check_eq!("John", user.name);
+    /// ```
+    pub async fn parse<T: serde::de::DeserializeOwned>(self) -> Result<T, Error> {
+        let content_type = self
+            .headers
+            .get(header::CONTENT_TYPE)
+            .and_then(|v| v.to_str().ok())
+            .unwrap_or_default();
+        let mime = content_type
+            .split(';')
+            .next()
+            .unwrap_or_default()
+            .trim()
+            .to_lowercase();
+
+        match mime.as_str() {
+            "application/json" => Ok(serde_json::from_str(&self.text)?),
+            m if m.ends_with("+json") => Ok(serde_json::from_str(&self.text)?),
+            "application/x-www-form-urlencoded" => Ok(serde_urlencoded::from_str(&self.text)?),
+            #[cfg(feature = "xml")]
+            "application/xml" | "text/xml" => Ok(quick_xml::de::from_str(&self.text)?),
+            #[cfg(feature = "xml")]
+            m if m.ends_with("+xml") => Ok(quick_xml::de::from_str(&self.text)?),
+            #[cfg(not(feature = "xml"))]
+            m if m == "application/xml" || m == "text/xml" || m.ends_with("+xml") => {
+                Err(Error::FeatureRequired(content_type.to_string(), "xml"))
+            }
+            #[cfg(feature = "msgpack")]
+            "application/msgpack" | "application/x-msgpack" | "application/vnd.msgpack" => {
+                Ok(rmp_serde::from_slice(&self.body)?)
+            }
+            #[cfg(not(feature = "msgpack"))]
+            "application/msgpack" | "application/x-msgpack" | "application/vnd.msgpack" => {
+                Err(Error::FeatureRequired(content_type.to_string(), "msgpack"))
+            }
+            _ => Err(Error::UnsupportedContentType(content_type.to_string())),
+        }
+    }
+
     #[cfg(feature = "cookies")]
     pub fn cookies(&self) -> impl Iterator<Item = &cookie::Cookie<'static>> + '_ {
         self.cookies.iter()
@@ -308,19 +398,21 @@ impl Response {
         let body_bytes = res.into_body().collect().await?.to_bytes();
 
         // Handle content decompression
-        let text = Self::decompress_body(&headers, &body_bytes);
+        let body = Self::decompress_body(&headers, body_bytes);
+        let text = String::from_utf8_lossy(&body).into_owned();
 
         Ok(Response {
             headers,
             status,
             url,
             text,
+            body,
             #[cfg(feature = "cookies")]
             cookies,
         })
     }
 
-    fn decompress_body(headers: &header::HeaderMap, body_bytes: &Bytes) -> String {
+    fn decompress_body(headers: &header::HeaderMap, body_bytes: Bytes) -> Bytes {
         match headers
             .get("content-encoding")
             .and_then(|v| v.to_str().ok())
@@ -330,8 +422,8 @@ impl Response {
                 let mut decoder = GzDecoder::new(body_bytes.as_ref());
                 let mut decompressed = Vec::new();
                 match decoder.read_to_end(&mut decompressed) {
-                    Ok(_) => String::from_utf8_lossy(&decompressed).to_string(),
-                    Err(_) => String::from_utf8_lossy(body_bytes).to_string(),
+                    Ok(_) => decompressed.into(),
+                    Err(_) => body_bytes,
                 }
             }
             Some("deflate") => {
@@ -341,14 +433,14 @@ impl Response {
                 let mut zlib_decoder = ZlibDecoder::new(body_bytes.as_ref());
                 let mut decompressed = Vec::new();
                 match zlib_decoder.read_to_end(&mut decompressed) {
-                    Ok(_) => String::from_utf8_lossy(&decompressed).to_string(),
+                    Ok(_) => decompressed.into(),
                     Err(_) => {
                         // Fallback to raw deflate format
                         let mut deflate_decoder = DeflateDecoder::new(body_bytes.as_ref());
                         let mut decompressed = Vec::new();
                         match deflate_decoder.read_to_end(&mut decompressed) {
-                            Ok(_) => String::from_utf8_lossy(&decompressed).to_string(),
-                            Err(_) => String::from_utf8_lossy(body_bytes).to_string(),
+                            Ok(_) => decompressed.into(),
+                            Err(_) => body_bytes,
                         }
                     }
                 }
@@ -358,15 +450,15 @@ impl Response {
                 match brotli_decompressor::Decompressor::new(body_bytes.as_ref(), 4096)
                     .read_to_end(&mut decompressed)
                 {
-                    Ok(_) => String::from_utf8_lossy(&decompressed).to_string(),
-                    Err(_) => String::from_utf8_lossy(body_bytes).to_string(),
+                    Ok(_) => decompressed.into(),
+                    Err(_) => body_bytes,
                 }
             }
             Some("zstd") => match zstd::decode_all(body_bytes.as_ref()) {
-                Ok(decompressed) => String::from_utf8_lossy(&decompressed).to_string(),
-                Err(_) => String::from_utf8_lossy(body_bytes).to_string(),
+                Ok(decompressed) => decompressed.into(),
+                Err(_) => body_bytes,
             },
-            _ => String::from_utf8_lossy(body_bytes).to_string(),
+            _ => body_bytes,
         }
     }
 }
@@ -789,21 +881,15 @@ impl RequestBuilder {
                     }
                 }
 
+                let (body, binary_body) = log_body(&response);
                 let log_response = LogResponse {
                     headers: if masking::should_mask_sensitive() {
                         masking::mask_headers(&response.headers)
                     } else {
                         response.headers.clone()
                     },
-                    body: if masking::should_mask_sensitive() {
-                        let content_type = response
-                            .headers
-                            .get(header::CONTENT_TYPE)
-                            .and_then(|v| v.to_str().ok());
-                        masking::mask_body(response.text.as_bytes(), content_type)
-                    } else {
-                        response.text.clone()
-                    },
+                    body,
+                    binary_body,
                     status: response.status(),
                     duration_req,
                 };
@@ -864,21 +950,15 @@ impl RequestBuilder {
                     }
                 }
 
+                let (body, binary_body) = log_body(&final_response);
                 let log_response = LogResponse {
                     headers: if masking::should_mask_sensitive() {
                         masking::mask_headers(&final_response.headers)
                     } else {
                         final_response.headers.clone()
                     },
-                    body: if masking::should_mask_sensitive() {
-                        let content_type = final_response
-                            .headers
-                            .get(header::CONTENT_TYPE)
-                            .and_then(|v| v.to_str().ok());
-                        masking::mask_body(final_response.text.as_bytes(), content_type)
-                    } else {
-                        final_response.text.clone()
-                    },
+                    body,
+                    binary_body,
                     status: final_response.status(),
                     duration_req,
                 };
@@ -933,21 +1013,15 @@ impl RequestBuilder {
                     let final_response = Response::from(response, current_url).await?;
                     let duration_req = start_time.elapsed();
 
+                    let (body, binary_body) = log_body(&final_response);
                     let log_response = LogResponse {
                         headers: if masking::should_mask_sensitive() {
                             masking::mask_headers(&final_response.headers)
                         } else {
                             final_response.headers.clone()
                         },
-                        body: if masking::should_mask_sensitive() {
-                            let content_type = final_response
-                                .headers
-                                .get(header::CONTENT_TYPE)
-                                .and_then(|v| v.to_str().ok());
-                            masking::mask_body(final_response.text.as_bytes(), content_type)
-                        } else {
-                            final_response.text.clone()
-                        },
+                        body,
+                        binary_body,
                         status: final_response.status(),
                         duration_req,
                     };
@@ -1051,3 +1125,158 @@ impl RequestBuilder {
         self
     }
 }
+
+/// Builds the logged response body, masking sensitive values when enabled.
+///
+/// The raw bytes of a non-UTF-8 body are kept so that exporters can encode it
+/// losslessly, but only when masking left the body unchanged. Otherwise the
+/// raw bytes would expose values that are masked in the text.
+fn log_body(response: &Response) -> (String, Option<Bytes>) {
+    let raw = std::str::from_utf8(&response.body)
+        .is_err()
+        .then(|| response.body.clone());
+    if !masking::should_mask_sensitive() {
+        return (response.text.clone(), raw);
+    }
+
+    let content_type = response
+        .headers
+        .get(header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok());
+    let masked = masking::mask_body(response.text.as_bytes(), content_type);
+    let raw = raw.filter(|_| masked == response.text);
+    (masked, raw)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[derive(Debug, PartialEq, serde::Deserialize)]
+    struct User {
+        name: String,
+        id: u64,
+    }
+
+    fn response_with(content_type: Option<&str>, body: &str) -> Response {
+        let mut headers = header::HeaderMap::new();
+        if let Some(content_type) = content_type {
+            headers.insert(
+                header::CONTENT_TYPE,
+                header::HeaderValue::from_str(content_type).unwrap(),
+            );
+        }
+        Response {
+            headers,
+            status: StatusCode::OK,
+            text: body.to_string(),
+            url: url::Url::parse("http://localhost/").unwrap(),
+            body: Bytes::copy_from_slice(body.as_bytes()),
+            #[cfg(feature = "cookies")]
+            cookies: Vec::new(),
+        }
+    }
+
+    fn binary_response_with(content_type: &str, body: &'static [u8]) -> Response {
+        let mut res = response_with(Some(content_type), "");
+        res.text = String::from_utf8_lossy(body).into_owned();
+        res.body = Bytes::from_static(body);
+        res
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn log_body_keeps_unmaskable_binary_body() {
+        masking::set_mask_sensitive(true);
+        let res = binary_response_with("image/png", &[0x89, b'P', b'N', b'G', 0xff]);
+
+        let (_, raw) = log_body(&res);
+        assert_eq!(raw.as_deref(), Some(&[0x89, b'P', b'N', b'G', 0xff][..]));
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn log_body_drops_binary_body_when_masked() {
+        masking::set_mask_sensitive(true);
+        let res = binary_response_with("application/json", b"{\"password\":\"\xff\"}");
+
+        let (text, raw) = log_body(&res);
+        assert_eq!(text, r#"{"password":"*****"}"#);
+        assert!(raw.is_none(), "raw body would leak the masked value");
+    }
+
+    #[tokio::test]
+    async fn parse_json_body() -> eyre::Result<()> {
+        let res = response_with(
+            Some("application/json; charset=utf-8"),
+            r#"{"name":"John","id":1}"#,
+        );
+        let user: User = res.parse().await?;
+        assert_eq!(
+            user,
+            User {
+                name: "John".into(),
+                id: 1
+            }
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn parse_json_suffix_body() -> eyre::Result<()> {
+        let res = response_with(
+            Some("application/problem+json"),
+            r#"{"name":"John","id":1}"#,
+        );
+        let user: User = res.parse().await?;
+        assert_eq!(user.name, "John");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn parse_form_body() -> eyre::Result<()> {
+        let res = response_with(Some("application/x-www-form-urlencoded"), "name=John&id=1");
+        let user: User = res.parse().await?;
+        assert_eq!(
+            user,
+            User {
+                name: "John".into(),
+                id: 1
+            }
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn parse_unsupported_content_type() {
+        let res = response_with(Some("text/plain"), "John");
+        let err = res.parse::<User>().await.unwrap_err();
+        assert!(matches!(err, Error::UnsupportedContentType(ref ct) if ct == "text/plain"));
+    }
+
+    #[cfg(not(feature = "xml"))]
+    #[tokio::test]
+    async fn parse_xml_without_feature() {
+        let res = response_with(Some("application/xml"), "<User/>");
+        let err = res.parse::<User>().await.unwrap_err();
+        assert!(matches!(err, Error::FeatureRequired(ref ct, "xml") if ct == "application/xml"));
+    }
+
+    #[cfg(not(feature = "msgpack"))]
+    #[tokio::test]
+    async fn parse_msgpack_without_feature() {
+        let res = response_with(Some("application/msgpack"), "");
+        let err = res.parse::<User>().await.unwrap_err();
+        assert!(
+            matches!(err, Error::FeatureRequired(ref ct, "msgpack") if ct == "application/msgpack")
+        );
+    }
+
+    #[tokio::test]
+    async fn parse_missing_content_type() {
+        let res = response_with(None, r#"{"name":"John","id":1}"#);
+        let err = res.parse::<User>().await.unwrap_err();
+        assert!(matches!(err, Error::UnsupportedContentType(_)));
+    }
+}
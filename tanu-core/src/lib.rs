@@ -32,6 +32,10 @@
 //!
 //! Most users should use the main `tanu` crate rather than importing `tanu-core` directly.
 
+// Lets the assertion macros, which expand to `tanu::` paths, be used in unit tests.
+#[cfg(test)]
+extern crate self as tanu;
+
 #[doc(hidden)]
 pub mod assertion;
 pub mod config;
@@ -78,7 +82,7 @@ pub type TestName = String;
 // Re-export key functionality
 pub use config::{get_config, get_tanu_config, CaptureHttpMode, Config, ProjectConfig};
 pub use error::{Error, Result};
-pub use reporter::{ListReporter, NullReporter, Reporter};
+pub use reporter::{HarReporter, ListReporter, NullReporter, Reporter};
 pub use runner::{
     CallLog, Filter, ModuleFilter, ProjectFilter, Runner, TestIgnoreFilter, TestInfo,
     TestNameFilter,
@@ -9,6 +9,7 @@
 //!
 //! - **`NullReporter`**: No output (useful for testing)
 //! - **`ListReporter`**: Real-time streaming output with detailed logs
+//! - **`HarReporter`**: Exports captured HTTP calls as a HAR file
 //!
 //! ## Custom Reporters
 //!
@@ -35,8 +36,13 @@
 //! ```
 
 use console::{style, StyledObject, Term};
+use eyre::WrapErr;
 use indexmap::IndexMap;
-use std::sync::{LazyLock, Mutex};
+use std::{
+    path::PathBuf,
+    sync::{LazyLock, Mutex},
+    time::SystemTime,
+};
 use tokio::sync::broadcast;
 use tracing::*;
 
@@ -93,7 +99,25 @@ async fn run<R: Reporter + Send + ?Sized>(reporter: &mut R) -> eyre::Result<()>
                 module,
                 test,
                 body: EventBody::Call(log),
-            }) => reporter.on_call(project, module, test, log).await,
+            }) => {
+                // Both hooks take the log by value, so `on_grpc_call` receives a
+                // clone of it and `on_call` the original.
+                #[cfg(feature = "grpc")]
+                if let runner::CallLog::Grpc(grpc_log) = &log {
+                    if let Err(e) = reporter
+                        .on_grpc_call(
+                            project.clone(),
+                            module.clone(),
+                            test.clone(),
+                            grpc_log.clone(),
+                        )
+                        .await
+                    {
+                        warn!("reporter error: {e:#}");
+                    }
+                }
+                reporter.on_call(project, module, test, log).await
+            }
             Ok(Event {
                 project,
                 module,
@@ -143,6 +167,7 @@ async fn run<R: Reporter + Send + ?Sized>(reporter: &mut R) -> eyre::Result<()>
 /// 1. `on_start()` - Test begins
 /// 2. `on_check()` - Each assertion (0 or more)
 /// 3. `on_call()` - Each protocol call (HTTP, gRPC, etc.) (0 or more)
+///    - `on_grpc_call()` - Additionally fired for each gRPC call, before `on_call()`
 /// 4. `on_retry()` - If test fails and retry is configured
 /// 5. `on_end()` - Test completes with final result
 ///
@@ -217,6 +242,19 @@ pub trait Reporter {
         Ok(())
     }
 
+    /// Called when a gRPC call is made. This is fired in addition to `on_call()`
+    /// with a clone of the same log, so implement only one of them for gRPC calls.
+    #[cfg(feature = "grpc")]
+    async fn on_grpc_call(
+        &mut self,
+        _project: String,
+        _module: String,
+        _test_name: String,
+        _log: Box<crate::grpc::Log>,
+    ) -> eyre::Result<()> {
+        Ok(())
+    }
+
     /// Called when a test case fails but to be retried.
     async fn on_retry(
         &mut self,
@@ -293,6 +331,7 @@ fn generate_test_number() -> usize {
 /// - **Retry indication**: Shows when tests are being retried
 /// - **Colored output**: Success/failure indicators with colors
 /// - **Test numbering**: Sequential numbering for easy reference
+/// - **Quarantine**: Failures of quarantined tests are listed in a separate section
 ///
 /// # Examples
 ///
@@ -323,6 +362,7 @@ pub struct ListReporter {
     terminal: Term,
     buffer: IndexMap<(ProjectName, ModuleName, TestName), Buffer>,
     capture_http: CaptureHttpMode,
+    quarantined_failures: Vec<String>,
 }
 
 impl ListReporter {
@@ -351,6 +391,7 @@ impl ListReporter {
             terminal: Term::stdout(),
             buffer: IndexMap::new(),
             capture_http,
+            quarantined_failures: Vec::new(),
         }
     }
 }
@@ -463,6 +504,7 @@ impl Reporter for ListReporter {
             started_at: _,
             ended_at: _,
             worker_id: _,
+            quarantined,
         } = test;
         let test_number = style(buffer.test_number.get_or_insert_with(generate_test_number)).dim();
         let request_time = style(format!("({request_time:.2?})")).dim();
@@ -474,6 +516,14 @@ impl Reporter for ListReporter {
                     "{status} {test_number} {project} {path} {request_time}"
                 ))?;
             }
+            Err(e) if quarantined => {
+                self.terminal.write_line(&format!(
+                    "{status} {test_number} {project} {path} {request_time} {label}:\n{error}",
+                    label = style("[quarantined]").yellow(),
+                    error = style(format!("{e:#}")).yellow()
+                ))?;
+                self.quarantined_failures.push(format!("{project} {path}"));
+            }
             Err(e) => {
                 self.terminal.write_line(&format!(
                     "{status} {test_number} {project} {path} {request_time}:\n{error}",
@@ -501,10 +551,21 @@ impl Reporter for ListReporter {
             passed_tests,
             failed_tests,
             skipped_tests,
+            quarantined_tests,
             total_time,
             test_prep_time,
         } = summary;
 
+        if !self.quarantined_failures.is_empty() {
+            self.terminal.write_line("")?;
+            self.terminal
+                .write_line(&style("Quarantined failures:").yellow().bold().to_string())?;
+            for failure in self.quarantined_failures.drain(..) {
+                self.terminal
+                    .write_line(&format!("  {} {failure}", style("-").dim()))?;
+            }
+        }
+
         self.terminal.write_line("")?;
         let mut summary_line = format!(
             "{}: {} {}, {} {}, {} {}",
@@ -531,6 +592,13 @@ impl Reporter for ListReporter {
                 style("skipped").yellow()
             ));
         }
+        if quarantined_tests > 0 {
+            summary_line.push_str(&format!(
+                ", {} {}",
+                style(quarantined_tests).yellow().bold(),
+                style("quarantined").yellow()
+            ));
+        }
         self.terminal.write_line(&summary_line)?;
         self.terminal.write_line(&format!(
             "{}: {} ({}: {})",
@@ -544,9 +612,85 @@ impl Reporter for ListReporter {
     }
 }
 
+/// A reporter that exports captured HTTP calls as a HAR (HTTP Archive) 1.2 file.
+///
+/// Every HTTP call made during the run becomes one HAR entry carrying the
+/// request/response headers, bodies, status and timing. The file is written
+/// once all tests complete and can be imported into browser devtools or Postman
+/// to share a reproduction. Sensitive values are masked unless
+/// `--show-sensitive` is used. Non-UTF-8 response bodies are base64 encoded,
+/// except when masking changed them, in which case the masked text is exported.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use tanu_core::{Runner, reporter::HarReporter};
+///
+/// let mut runner = Runner::new();
+/// runner.add_reporter(HarReporter::new("tanu.har"));
+/// ```
+pub struct HarReporter {
+    path: PathBuf,
+    entries: Vec<(SystemTime, serde_json::Value)>,
+}
+
+impl HarReporter {
+    /// Creates a new HAR reporter that writes to `path` at the end of the run.
+    pub fn new(path: impl Into<PathBuf>) -> HarReporter {
+        HarReporter {
+            path: path.into(),
+            entries: Vec::new(),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Reporter for HarReporter {
+    async fn on_call(
+        &mut self,
+        project_name: String,
+        module_name: String,
+        test_name: String,
+        log: runner::CallLog,
+    ) -> eyre::Result<()> {
+        match log {
+            runner::CallLog::Http(log) => {
+                let comment = format!("[{project_name}] {module_name}::{test_name}");
+                self.entries
+                    .push((log.started_at, har_entry(&log, &comment)));
+            }
+            // HAR only describes HTTP traffic
+            #[cfg(feature = "grpc")]
+            runner::CallLog::Grpc(_) => {}
+        }
+        Ok(())
+    }
+
+    async fn on_summary(&mut self, _summary: runner::TestSummary) -> eyre::Result<()> {
+        let mut entries = std::mem::take(&mut self.entries);
+        entries.sort_by_key(|(started_at, _)| *started_at);
+        let har = serde_json::json!({
+            "log": {
+                "version": "1.2",
+                "creator": {
+                    "name": "tanu",
+                    "version": env!("CARGO_PKG_VERSION"),
+                },
+                "entries": entries.into_iter().map(|(_, entry)| entry).collect::<Vec<_>>(),
+            }
+        });
+        let file = std::fs::File::create(&self.path)
+            .wrap_err_with(|| format!("failed to create HAR file {}", self.path.display()))?;
+        serde_json::to_writer_pretty(std::io::BufWriter::new(file), &har)
+            .wrap_err_with(|| format!("failed to write HAR file {}", self.path.display()))?;
+        Ok(())
+    }
+}
+
 fn symbol_test_result(test: &Test) -> StyledObject<&'static str> {
     match test.result {
         Ok(_) => symbol_success(),
+        Err(_) if test.quarantined => symbol_quarantined(),
         Err(_) => symbol_error(),
     }
 }
@@ -559,6 +703,10 @@ fn symbol_error() -> StyledObject<&'static str> {
     style("✘").red()
 }
 
+fn symbol_quarantined() -> StyledObject<&'static str> {
+    style("✘").yellow()
+}
+
 /// Color HTTP methods for visual distinction
 fn style_http_method(method: &str) -> StyledObject<&str> {
     match method.to_uppercase().as_str() {
@@ -817,10 +965,11 @@ fn write_grpc_log(terminal: &Term, log: &crate::grpc::Log) -> eyre::Result<()> {
         ))?;
     }
     terminal.write_line(&format!(
-        "  {} {} {}",
+        "  {} {} {} {}",
         style("<").yellow(),
         style("response:").yellow(),
-        style_grpc_status(log.response.status_code)
+        style_grpc_status(log.response.status_code),
+        style(format!("({:.2?})", log.response.duration)).dim()
     ))?;
     terminal.write_line(&format!(
         "    {} {}",
@@ -863,3 +1012,304 @@ fn write_grpc_log(terminal: &Term, log: &crate::grpc::Log) -> eyre::Result<()> {
     }
     Ok(())
 }
+
+/// Converts a captured HTTP call into a HAR 1.2 `entry` object.
+///
+/// Only the total request time is captured, so it is reported as the `wait`
+/// phase; header sizes and the HTTP version are unknown and left unset as the
+/// spec allows.
+fn har_entry(log: &http::Log, comment: &str) -> serde_json::Value {
+    use serde_json::json;
+
+    let request = &log.request;
+    let response = &log.response;
+    let time = response.duration_req.as_secs_f64() * 1000.0;
+
+    let mut har_request = json!({
+        "method": request.method.as_str(),
+        "url": request.url.as_str(),
+        "httpVersion": "",
+        "cookies": [],
+        "headers": har_headers(&request.headers),
+        "queryString": request
+            .url
+            .query_pairs()
+            .map(|(name, value)| json!({ "name": name, "value": value }))
+            .collect::<Vec<_>>(),
+        "headersSize": -1,
+        "bodySize": request.body.as_ref().map_or(0, |body| body.len()),
+    });
+    if let Some(body) = &request.body {
+        har_request["postData"] = json!({
+            "mimeType": har_mime_type(&request.headers),
+            "text": body,
+        });
+    }
+
+    let content = match &response.binary_body {
+        Some(bytes) => json!({
+            "size": bytes.len(),
+            "mimeType": har_mime_type(&response.headers),
+            "text": base64::Engine::encode(&base64::engine::general_purpose::STANDARD, bytes),
+            "encoding": "base64",
+        }),
+        None => json!({
+            "size": response.body.len(),
+            "mimeType": har_mime_type(&response.headers),
+            "text": response.body,
+        }),
+    };
+
+    json!({
+        "startedDateTime": chrono::DateTime::<chrono::Utc>::from(log.started_at)
+            .to_rfc3339_opts(chrono::SecondsFormat::Millis, true),
+        "time": time,
+        "request": har_request,
+        "response": {
+            "status": response.status.as_u16(),
+            "statusText": response.status.canonical_reason().unwrap_or_default(),
+            "httpVersion": "",
+            "cookies": [],
+            "headers": har_headers(&response.headers),
+            "content": content,
+            "redirectURL": response
+                .headers
+                .get(http::header::LOCATION)
+                .and_then(|v| v.to_str().ok())
+                .unwrap_or_default(),
+            "headersSize": -1,
+            "bodySize": -1,
+        },
+        "cache": {},
+        "timings": {
+            "send": 0,
+            "wait": time,
+            "receive": 0,
+        },
+        "comment": comment,
+    })
+}
+
+fn har_headers(headers: &http::header::HeaderMap) -> Vec<serde_json::Value> {
+    headers
+        .iter()
+        .map(|(name, value)| {
+            serde_json::json!({
+                "name": name.as_str(),
+                "value": String::from_utf8_lossy(value.as_bytes()),
+            })
+        })
+        .collect()
+}
+
+fn har_mime_type(headers: &http::header::HeaderMap) -> &str {
+    headers
+        .get(http::header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use bytes::Bytes;
+    use std::time::{Duration, SystemTime};
+
+    fn http_log(response: http::LogResponse) -> http::Log {
+        let mut headers = http::header::HeaderMap::new();
+        headers.insert(
+            http::header::CONTENT_TYPE,
+            "application/json".parse().unwrap(),
+        );
+        http::Log {
+            request: http::LogRequest {
+                url: "https://example.com/users?page=2&q=a%20b".parse().unwrap(),
+                method: http::Method::POST,
+                headers,
+                body: Some(r#"{"name":"tanu"}"#.to_string()),
+            },
+            response,
+            started_at: SystemTime::UNIX_EPOCH + Duration::from_millis(1_500),
+            ended_at: SystemTime::UNIX_EPOCH + Duration::from_millis(1_750),
+        }
+    }
+
+    #[test]
+    fn har_entry_text_body() {
+        let log = http_log(http::LogResponse {
+            body: "line1\n\"quoted\"".to_string(),
+            status: http::StatusCode::CREATED,
+            duration_req: Duration::from_millis(250),
+            ..Default::default()
+        });
+
+        let entry = har_entry(&log, "[dev] users::create");
+
+        assert_eq!(entry["startedDateTime"], "1970-01-01T00:00:01.500Z");
+        assert_eq!(entry["time"], 250.0);
+        assert_eq!(entry["timings"]["wait"], 250.0);
+        assert_eq!(entry["comment"], "[dev] users::create");
+        assert_eq!(entry["request"]["method"], "POST");
+        assert_eq!(
+            entry["request"]["queryString"],
+            serde_json::json!([
+                { "name": "page", "value": "2" },
+                { "name": "q", "value": "a b" },
+            ])
+        );
+        assert_eq!(
+            entry["request"]["postData"],
+            serde_json::json!({ "mimeType": "application/json", "text": r#"{"name":"tanu"}"# })
+        );
+        assert_eq!(entry["response"]["status"], 201);
+        assert_eq!(entry["response"]["statusText"], "Created");
+        assert_eq!(entry["response"]["content"]["text"], "line1\n\"quoted\"");
+        assert!(entry["response"]["content"].get("encoding").is_none());
+    }
+
+    #[test]
+    fn har_entry_binary_body_is_base64() {
+        let raw = Bytes::from_static(&[0xff, 0xd8, 0xff, 0x00]);
+        let log = http_log(http::LogResponse {
+            body: String::from_utf8_lossy(&raw).into_owned(),
+            binary_body: Some(raw),
+            ..Default::default()
+        });
+
+        let entry = har_entry(&log, "");
+
+        assert_eq!(entry["response"]["content"]["encoding"], "base64");
+        assert_eq!(entry["response"]["content"]["text"], "/9j/AA==");
+        assert_eq!(entry["response"]["content"]["size"], 4);
+    }
+
+    #[tokio::test]
+    async fn har_reporter_writes_entries_sorted_by_start_time() -> eyre::Result<()> {
+        let path = std::env::temp_dir().join(format!("tanu-har-{}.har", std::process::id()));
+        let mut reporter = HarReporter::new(&path);
+
+        let mut later = http_log(http::LogResponse::default());
+        later.started_at = SystemTime::UNIX_EPOCH + Duration::from_secs(20);
+        let mut earlier = http_log(http::LogResponse::default());
+        earlier.started_at = SystemTime::UNIX_EPOCH + Duration::from_secs(10);
+        for (test_name, log) in [("later", later), ("earlier", earlier)] {
+            reporter
+                .on_call(
+                    "dev".into(),
+                    "users".into(),
+                    test_name.into(),
+                    runner::CallLog::Http(Box::new(log)),
+                )
+                .await?;
+        }
+        reporter
+            .on_summary(runner::TestSummary {
+                total_tests: 2,
+                passed_tests: 2,
+                failed_tests: 0,
+                skipped_tests: 0,
+                quarantined_tests: 0,
+                total_time: Duration::ZERO,
+                test_prep_time: Duration::ZERO,
+            })
+            .await?;
+
+        let har: serde_json::Value = serde_json::from_reader(std::fs::File::open(&path)?)?;
+        std::fs::remove_file(&path)?;
+
+        assert_eq!(har["log"]["version"], "1.2");
+        assert_eq!(har["log"]["creator"]["name"], "tanu");
+        let comments = har["log"]["entries"]
+            .as_array()
+            .expect("entries should be an array")
+            .iter()
+            .map(|entry| entry["comment"].as_str().unwrap_or_default())
+            .collect::<Vec<_>>();
+        assert_eq!(comments, ["[dev] users::earlier", "[dev] users::later"]);
+
+        Ok(())
+    }
+
+    #[cfg(feature = "grpc")]
+    fn grpc_log() -> Box<crate::grpc::Log> {
+        Box::new(crate::grpc::Log {
+            request: crate::grpc::LogRequest {
+                method: "/echo.Echo/Unary".into(),
+                metadata: tonic::metadata::MetadataMap::new(),
+                message: Bytes::new(),
+            },
+            response: crate::grpc::LogResponse {
+                metadata: tonic::metadata::MetadataMap::new(),
+                message: Bytes::new(),
+                status_code: tonic::Code::Ok,
+                status_message: String::new(),
+                duration: Duration::from_millis(5),
+            },
+            started_at: SystemTime::UNIX_EPOCH,
+            ended_at: SystemTime::UNIX_EPOCH,
+        })
+    }
+
+    /// Records the names of the hooks it receives.
+    #[cfg(feature = "grpc")]
+    struct HookRecorder(tokio::sync::mpsc::UnboundedSender<&'static str>);
+
+    #[cfg(feature = "grpc")]
+    #[async_trait::async_trait]
+    impl Reporter for HookRecorder {
+        async fn on_call(
+            &mut self,
+            _project: String,
+            _module: String,
+            _test_name: String,
+            log: runner::CallLog,
+        ) -> eyre::Result<()> {
+            if let runner::CallLog::Grpc(_) = log {
+                self.0.send("on_call")?;
+            }
+            Ok(())
+        }
+
+        async fn on_grpc_call(
+            &mut self,
+            _project: String,
+            _module: String,
+            _test_name: String,
+            _log: Box<crate::grpc::Log>,
+        ) -> eyre::Result<()> {
+            self.0.send("on_grpc_call")?;
+            Ok(())
+        }
+    }
+
+    #[cfg(feature = "grpc")]
+    #[tokio::test]
+    #[serial_test::serial]
+    async fn run_dispatches_grpc_call_before_on_call() -> eyre::Result<()> {
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        let mut reporter = HookRecorder(tx);
+
+        // Wait until the reporter has subscribed before publishing
+        runner::setup_reporter_barrier(1)?;
+        let handle = tokio::spawn(async move { reporter.run().await });
+        runner::wait_reporter_barrier().await;
+        runner::clear_reporter_barrier();
+
+        runner::publish(Event {
+            project: "dev".into(),
+            module: "echo".into(),
+            test: "unary".into(),
+            body: EventBody::Call(runner::CallLog::Grpc(grpc_log())),
+        })?;
+
+        let mut hooks = Vec::new();
+        for _ in 0..2 {
+            let hook = tokio::time::timeout(Duration::from_secs(5), rx.recv()).await?;
+            hooks.extend(hook);
+        }
+        handle.abort();
+
+        assert_eq!(hooks, ["on_grpc_call", "on_call"]);
+        Ok(())
+    }
+}
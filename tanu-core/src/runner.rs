@@ -162,6 +162,18 @@ pub fn publish(e: impl Into<Event>) -> eyre::Result<()> {
     Ok(())
 }
 
+/// Publishes a check event, ignoring delivery failures.
+///
+/// Used by the `check!` family of macros so that the outcome of an assertion
+/// depends only on its condition. A closed channel is logged at debug level
+/// instead of being returned as an error.
+#[doc(hidden)]
+pub fn publish_check(check: Check) {
+    if let Err(e) = publish(EventBody::Check(Box::new(check))) {
+        debug!("failed to publish check event: {e:#}");
+    }
+}
+
 /// Subscribe to the channel to see the real-time test execution events.
 pub fn subscribe() -> eyre::Result<broadcast::Receiver<Event>> {
     let Ok(guard) = CHANNEL.lock() else {
@@ -210,6 +222,7 @@ async fn execute_test(
     factory: TestCaseFactory,
     serial_mutex: Option<Arc<tokio::sync::Mutex<()>>>,
     worker_id: isize,
+    sla: Option<Duration>,
 ) -> eyre::Result<Test> {
     let project_for_scope = Arc::clone(&project);
     let info_for_scope = Arc::clone(&info);
@@ -218,10 +231,17 @@ async fn execute_test(
             TEST_INFO
                 .scope(info_for_scope, async {
                     let test_name = info.name.clone();
+                    let quarantined = project
+                        .quarantine
+                        .iter()
+                        .any(|name| name == &info.full_name());
                     publish(EventBody::Start)?;
 
                     let retry_count = AtomicUsize::new(project.retry.count.unwrap_or(0));
                     let serial_mutex_clone = serial_mutex.clone();
+                    // Duration of the last attempt, excluding the wait for the serial guard
+                    // and retry backoff. The SLA is checked against this.
+                    let attempt_time = std::sync::Mutex::new(Duration::ZERO);
                     let f = || async {
                         // Acquire serial guard just before test execution
                         let _serial_guard = if let Some(ref mutex) = serial_mutex_clone {
@@ -234,6 +254,7 @@ async fn execute_test(
                         let request_started = std::time::Instant::now();
                         let res = factory().await;
                         let ended_at = SystemTime::now();
+                        *attempt_time.lock().unwrap() = request_started.elapsed();
 
                         if res.is_err() && retry_count.load(Ordering::SeqCst) > 0 {
                             let test_result = match &res {
@@ -247,6 +268,7 @@ async fn execute_test(
                                 started_at,
                                 ended_at,
                                 request_time: request_started.elapsed(),
+                                quarantined,
                             };
                             publish(EventBody::Retry(test))?;
                             retry_count.fetch_sub(1, Ordering::SeqCst);
@@ -284,6 +306,20 @@ async fn execute_test(
                         }
                     };
 
+                    let attempt_time = *attempt_time.lock().unwrap();
+                    let result = match sla {
+                        Some(limit) if result.is_ok() && attempt_time > limit => {
+                            let message = format!(
+                                "{} took {attempt_time:.2?}, exceeding the SLA of {limit:?}",
+                                info.full_name()
+                            );
+                            debug!("{test_name} violated SLA: {message}");
+                            publish(EventBody::Check(Box::new(Check::error(&message))))?;
+                            Err(Error::SlaViolated(message))
+                        }
+                        _ => result,
+                    };
+
                     let test = Test {
                         result,
                         info: Arc::clone(&info),
@@ -291,6 +327,7 @@ async fn execute_test(
                         started_at,
                         ended_at,
                         request_time,
+                        quarantined,
                     };
 
                     publish(EventBody::End(test.clone()))?;
@@ -302,6 +339,15 @@ async fn execute_test(
         .await
 }
 
+/// Looks up the SLA of the test in the project, by unique name first and then by full name.
+fn sla_for(project: &ProjectConfig, info: &TestInfo) -> Option<Duration> {
+    project
+        .sla
+        .get(&info.unique_name(&project.name))
+        .or_else(|| project.sla.get(&info.full_name()))
+        .copied()
+}
+
 /// Clear barrier after use.
 pub(crate) fn clear_reporter_barrier() {
     match REPORTER_BARRIER.lock() {
@@ -324,6 +370,8 @@ pub enum Error {
     Panicked(String),
     #[error("error: {0}")]
     ErrorReturned(String),
+    #[error("sla violation: {0}")]
+    SlaViolated(String),
 }
 
 /// Represents the result of a check/assertion within a test.
@@ -442,6 +490,9 @@ pub struct Test {
     pub ended_at: SystemTime,
     pub request_time: Duration,
     pub result: Result<(), Error>,
+    /// Whether the test is quarantined in the project. Failures of quarantined
+    /// tests are reported but do not make the test run fail.
+    pub quarantined: bool,
 }
 
 /// Overall test execution summary.
@@ -451,14 +502,43 @@ pub struct Test {
 /// This is published in the `Summary` event when all tests complete.
 #[derive(Debug, Clone)]
 pub struct TestSummary {
+    /// Number of scheduled test cases, counting each project separately.
     pub total_tests: usize,
     pub passed_tests: usize,
     pub failed_tests: usize,
     pub skipped_tests: usize,
+    /// Number of quarantined tests that failed.
+    pub quarantined_tests: usize,
     pub total_time: Duration,
     pub test_prep_time: Duration,
 }
 
+/// Per-test outcomes reported by a spawned test task.
+///
+/// An ordered group runs several tests in a single task, so the counts are
+/// kept per test to stay consistent with `TestSummary::total_tests`.
+#[derive(Debug, Default, Clone, Copy)]
+struct TaskCounts {
+    passed: usize,
+    failed: usize,
+    quarantined: usize,
+}
+
+impl TaskCounts {
+    fn record(&mut self, test: &Test) {
+        match (&test.result, test.quarantined) {
+            (Ok(_), _) => self.passed += 1,
+            (Err(_), true) => self.quarantined += 1,
+            (Err(_), false) => self.failed += 1,
+        }
+    }
+
+    fn record_error(&mut self, e: eyre::Report) {
+        debug!("test case failed: {e:#}");
+        self.failed += 1;
+    }
+}
+
 /// Test metadata and identification.
 ///
 /// Contains the module and test name for a test case. This information
@@ -471,6 +551,9 @@ pub struct TestInfo {
     pub serial_group: Option<String>,
     pub line: u32,
     pub ordered: bool,
+    /// Human-readable description taken from the test's doc comment. Empty if
+    /// the test has no doc comment.
+    pub description: String,
 }
 
 impl TestInfo {
@@ -578,6 +661,9 @@ pub struct Options {
     pub extra_sensitive_keys: Vec<String>,
     /// Extra header names (exact, case-insensitive) to treat as sensitive (see `tanu.toml`).
     pub extra_sensitive_headers: Vec<String>,
+    /// Whether to fail tests exceeding their SLA configured in `tanu.toml`.
+    /// Defaults to `true`. Set to `false` with `--no-sla` flag.
+    pub enforce_sla: bool,
 }
 
 impl Default for Options {
@@ -592,6 +678,7 @@ impl Default for Options {
             fail_fast: false,
             extra_sensitive_keys: Vec::new(),
             extra_sensitive_headers: Vec::new(),
+            enforce_sla: true,
         }
     }
 }
@@ -949,16 +1036,25 @@ impl Runner {
         ordered: bool,
         factory: TestCaseFactory,
     ) {
-        self.test_cases.push((
-            Arc::new(TestInfo {
+        self.add_test_info(
+            TestInfo {
                 name: name.into(),
                 module: module.into(),
                 serial_group: serial_group.map(|s| s.to_string()),
                 line,
                 ordered,
-            }),
+                description: String::new(),
+            },
             factory,
-        ));
+        );
+    }
+
+    /// Add a test case with all of its metadata to the runner.
+    ///
+    /// Use this instead of [`Runner::add_test`] to set optional fields such as
+    /// `description`.
+    pub fn add_test_info(&mut self, info: TestInfo, factory: TestCaseFactory) {
+        self.test_cases.push((Arc::new(info), factory));
     }
 
     /// Sets the maximum number of tests to run concurrently.
@@ -1007,6 +1103,18 @@ impl Runner {
         self.options.fail_fast = fail_fast;
     }
 
+    /// Disables enforcement of the per-test SLAs configured in `tanu.toml`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// let mut runner = Runner::new();
+    /// runner.disable_sla(); // Slow tests no longer fail
+    /// ```
+    pub fn disable_sla(&mut self) {
+        self.options.enforce_sla = false;
+    }
+
     /// Executes all registered tests with optional filtering.
     ///
     /// Runs tests concurrently according to the configured options and filters.
@@ -1078,8 +1186,9 @@ impl Runner {
 
         let start = std::time::Instant::now();
         let fail_fast = self.options.fail_fast;
+        let enforce_sla = self.options.enforce_sla;
         let cancelled = Arc::new(AtomicBool::new(false));
-        let handles: FuturesUnordered<_> = {
+        let (handles, total_tests): (FuturesUnordered<_>, usize) = {
             // Create a semaphore to limit concurrency
             let concurrency = self.options.concurrency;
             let semaphore = Arc::new(tokio::sync::Semaphore::new(
@@ -1116,6 +1225,7 @@ impl Runner {
                 .filter(move |(project, info, _)| project_filter.filter(project, info))
                 .filter(move |(project, info, _)| test_ignore_filter.filter(project, info))
                 .collect();
+            let total_tests = all_tests.len();
 
             // Separate ordered and non-ordered tests
             let (mut ordered_tests, non_ordered_tests): (Vec<_>, Vec<_>) =
@@ -1158,8 +1268,7 @@ impl Runner {
                     };
 
                     // Run all tests in this group sequentially (await each before starting next)
-                    let mut group_failed = false;
-                    let mut group_error: Option<eyre::Report> = None;
+                    let mut counts = TaskCounts::default();
                     for (project, info, factory) in tests {
                         if cancelled.load(Ordering::Relaxed) {
                             break;
@@ -1178,37 +1287,24 @@ impl Runner {
                         // Acquire worker ID
                         let worker_id = worker_ids.acquire();
 
+                        let sla = enforce_sla.then(|| sla_for(&project, &info)).flatten();
                         let result = execute_test(
                             project,
                             info,
                             factory,
                             Some(serial_mutex.clone()),
                             worker_id,
+                            sla,
                         )
                         .await;
                         worker_ids.release(worker_id);
 
                         match result {
-                            Ok(test) => {
-                                if test.result.is_err() {
-                                    group_failed = true;
-                                }
-                            }
-                            Err(e) => {
-                                group_failed = true;
-                                if group_error.is_none() {
-                                    group_error = Some(e);
-                                }
-                            }
-                        }
-                    }
-                    if group_failed {
-                        if let Some(e) = group_error {
-                            return Err(e);
+                            Ok(test) => counts.record(&test),
+                            Err(e) => counts.record_error(e),
                         }
-                        eyre::bail!("one or more tests failed");
                     }
-                    eyre::Ok(())
+                    counts
                 })
             });
 
@@ -1222,8 +1318,9 @@ impl Runner {
                         let serial_groups = serial_groups.clone();
                         let cancelled = cancelled.clone();
                         tokio::spawn(async move {
+                            let mut counts = TaskCounts::default();
                             if cancelled.load(Ordering::Relaxed) {
-                                return Ok(());
+                                return counts;
                             }
 
                             // Step 1: Acquire serial group mutex FIRST (if needed) - project-scoped
@@ -1255,32 +1352,38 @@ impl Runner {
 
                             // Step 2: Acquire global semaphore AFTER serial mutex
                             // This prevents blocking other tests while waiting for serial group
-                            let _permit = semaphore
-                                .acquire()
-                                .await
-                                .map_err(|e| eyre::eyre!("failed to acquire semaphore: {e}"))?;
+                            let _permit = match semaphore.acquire().await {
+                                Ok(permit) => permit,
+                                Err(e) => {
+                                    counts.record_error(eyre::eyre!(
+                                        "failed to acquire semaphore: {e}"
+                                    ));
+                                    return counts;
+                                }
+                            };
 
                             // Acquire worker ID from pool
                             let worker_id = worker_ids.acquire();
 
+                            let sla = enforce_sla.then(|| sla_for(&project, &info)).flatten();
                             let result = execute_test(
                                 project,
                                 info,
                                 factory,
                                 serial_mutex.clone(),
                                 worker_id,
+                                sla,
                             )
-                            .await
-                            .and_then(|test| {
-                                let is_err = test.result.is_err();
-                                eyre::ensure!(!is_err);
-                                eyre::Ok(())
-                            });
+                            .await;
 
                             // Return worker ID to pool
                             worker_ids.release(worker_id);
 
-                            result
+                            match result {
+                                Ok(test) => counts.record(&test),
+                                Err(e) => counts.record_error(e),
+                            }
+                            counts
                         })
                     });
 
@@ -1292,7 +1395,7 @@ impl Runner {
             for handle in non_ordered_handles {
                 all_handles.push(handle);
             }
-            all_handles
+            (all_handles, total_tests)
         };
         let test_prep_time = start.elapsed();
         debug!(
@@ -1300,50 +1403,40 @@ impl Runner {
             test_prep_time.as_secs_f32()
         );
 
-        let mut has_any_error = false;
-        let total_tests = handles.len();
         let options = self.options.clone();
         let runner = async move {
             let mut handles = handles;
+            let mut passed_tests = 0;
             let mut failed_tests = 0;
-            let mut processed_tests = 0;
+            let mut quarantined_tests = 0;
 
             while let Some(result) = handles.next().await {
-                processed_tests += 1;
                 match result {
-                    Ok(res) => {
-                        if let Err(e) = res {
-                            debug!("test case failed: {e:#}");
-                            has_any_error = true;
-                            failed_tests += 1;
-                            if fail_fast {
-                                cancelled.store(true, Ordering::Relaxed);
-                                break;
-                            }
-                        }
+                    Ok(counts) => {
+                        passed_tests += counts.passed;
+                        failed_tests += counts.failed;
+                        quarantined_tests += counts.quarantined;
                     }
                     Err(e) => {
                         if e.is_panic() {
                             // Resume the panic on the main task
                             error!("{e}");
-                            has_any_error = true;
                             failed_tests += 1;
-                            if fail_fast {
-                                cancelled.store(true, Ordering::Relaxed);
-                                break;
-                            }
                         }
                     }
                 }
+                if fail_fast && failed_tests > 0 {
+                    cancelled.store(true, Ordering::Relaxed);
+                    break;
+                }
             }
 
             if total_tests == 0 {
                 console::Term::stdout().write_line("no test cases found")?;
             }
 
-            // Count remaining skipped tasks (when fail-fast triggered early exit)
-            let skipped_tests = total_tests - processed_tests;
-            let passed_tests = total_tests - failed_tests - skipped_tests;
+            // Tests that never ran (fail-fast triggered early exit) are skipped
+            let skipped_tests = total_tests - passed_tests - failed_tests - quarantined_tests;
             let total_time = start.elapsed();
 
             // Publish summary event
@@ -1352,6 +1445,7 @@ impl Runner {
                 passed_tests,
                 failed_tests,
                 skipped_tests,
+                quarantined_tests,
                 total_time,
                 test_prep_time,
             };
@@ -1378,7 +1472,7 @@ impl Runner {
                 guard.take(); // closing the runner channel.
             }
 
-            if has_any_error {
+            if failed_tests > 0 {
                 eyre::bail!("one or more tests failed");
             }
 
@@ -1553,6 +1647,7 @@ mod test {
             serial_group: None,
             line: 0,
             ordered: false,
+            description: String::new(),
         });
 
         crate::config::PROJECT
@@ -1582,6 +1677,7 @@ mod test {
             serial_group: None,
             line: 0,
             ordered: false,
+            description: String::new(),
         });
 
         crate::config::PROJECT
@@ -1599,6 +1695,48 @@ mod test {
             .await;
     }
 
+    #[tokio::test]
+    #[serial_test::serial]
+    async fn passing_check_succeeds_when_channel_is_closed() {
+        let project = Arc::new(ProjectConfig {
+            name: "default".to_string(),
+            ..Default::default()
+        });
+        let test_info = Arc::new(TestInfo {
+            module: "mod".to_string(),
+            name: "test".to_string(),
+            serial_group: None,
+            line: 0,
+            ordered: false,
+            description: String::new(),
+        });
+
+        let channel = CHANNEL.lock().unwrap().take();
+        let (passing, failing) = crate::config::PROJECT
+            .scope(
+                project,
+                TEST_INFO.scope(test_info, async move {
+                    let passing = async {
+                        crate::check!(1 + 1 == 2);
+                        eyre::Ok(())
+                    }
+                    .await;
+                    let failing = async {
+                        crate::check!(1 + 1 == 3);
+                        eyre::Ok(())
+                    }
+                    .await;
+                    (passing, failing)
+                }),
+            )
+            .await;
+        *CHANNEL.lock().unwrap() = channel;
+
+        assert!(passing.is_ok());
+        let err = failing.unwrap_err().to_string();
+        assert!(err.contains("check failed: 1 + 1 == 3"), "{err}");
+    }
+
     #[tokio::test]
     #[serial_test::serial]
     async fn masking_masks_sensitive_query_params_in_http_logs() -> eyre::Result<()> {
@@ -2080,6 +2218,287 @@ mod test {
         Ok(())
     }
 
+    #[tokio::test]
+    #[serial_test::serial]
+    async fn runner_quarantined_failure_does_not_fail_run() -> eyre::Result<()> {
+        let mut rx = subscribe()?;
+        let mut runner = Runner::with_config(Config {
+            projects: vec![Arc::new(ProjectConfig {
+                name: "default".into(),
+                quarantine: vec!["module::q_fail".into()],
+                ..Default::default()
+            })],
+            ..Default::default()
+        });
+        runner.set_concurrency(1);
+
+        runner.add_test("q_fail", "module", None, 0, false, failing_factory());
+        runner.add_test("q_pass", "module", None, 1, false, passing_factory());
+
+        let result = runner.run(&[], &[], &[]).await;
+        assert!(
+            result.is_ok(),
+            "quarantined failure should not fail the run"
+        );
+
+        let mut summary = None;
+        let mut quarantined_end = None;
+        while let Ok(event) = rx.try_recv() {
+            match event.body {
+                EventBody::Summary(s) => summary = Some(s),
+                EventBody::End(test) if event.test == "q_fail" => quarantined_end = Some(test),
+                _ => {}
+            }
+        }
+
+        let test = quarantined_end.expect("should have received End event for q_fail");
+        assert!(test.quarantined);
+        assert!(test.result.is_err());
+
+        let summary = summary.expect("should have received Summary event");
+        assert_eq!(summary.failed_tests, 0);
+        assert_eq!(summary.quarantined_tests, 1);
+        assert_eq!(summary.passed_tests, 1);
+
+        Ok(())
+    }
+
+    fn create_config_with_quarantine(tests: &[&str]) -> Config {
+        Config {
+            projects: vec![Arc::new(ProjectConfig {
+                name: "default".into(),
+                quarantine: tests.iter().map(|test| test.to_string()).collect(),
+                ..Default::default()
+            })],
+            ..Default::default()
+        }
+    }
+
+    async fn run_and_get_summary(runner: Runner) -> eyre::Result<(eyre::Result<()>, TestSummary)> {
+        let mut rx = subscribe()?;
+        let mut runner = runner;
+        let result = runner.run(&[], &[], &[]).await;
+
+        let mut summary = None;
+        while let Ok(event) = rx.try_recv() {
+            if let EventBody::Summary(s) = event.body {
+                summary = Some(s);
+            }
+        }
+        let summary = summary.ok_or_else(|| eyre::eyre!("should have received Summary event"))?;
+        Ok((result, summary))
+    }
+
+    #[tokio::test]
+    #[serial_test::serial]
+    async fn runner_quarantined_failures_in_serial_group_are_counted_per_test() -> eyre::Result<()>
+    {
+        let mut runner = Runner::with_config(create_config_with_quarantine(&[
+            "module::qg_fail1",
+            "module::qg_fail2",
+        ]));
+        runner.add_test(
+            "qg_fail1",
+            "module",
+            Some("group"),
+            0,
+            true,
+            failing_factory(),
+        );
+        runner.add_test(
+            "qg_fail2",
+            "module",
+            Some("group"),
+            1,
+            true,
+            failing_factory(),
+        );
+        runner.add_test(
+            "qg_pass",
+            "module",
+            Some("group"),
+            2,
+            true,
+            passing_factory(),
+        );
+
+        let (result, summary) = run_and_get_summary(runner).await?;
+        assert!(
+            result.is_ok(),
+            "quarantined failures should not fail the run"
+        );
+        assert_eq!(summary.total_tests, 3);
+        assert_eq!(summary.passed_tests, 1);
+        assert_eq!(summary.failed_tests, 0);
+        assert_eq!(summary.quarantined_tests, 2);
+        assert_eq!(summary.skipped_tests, 0);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[serial_test::serial]
+    async fn runner_serial_group_failure_keeps_quarantined_count() -> eyre::Result<()> {
+        let mut runner =
+            Runner::with_config(create_config_with_quarantine(&["module::qgf_quarantined"]));
+        runner.add_test(
+            "qgf_quarantined",
+            "module",
+            Some("group"),
+            0,
+            true,
+            failing_factory(),
+        );
+        runner.add_test(
+            "qgf_fail",
+            "module",
+            Some("group"),
+            1,
+            true,
+            failing_factory(),
+        );
+        runner.add_test(
+            "qgf_pass",
+            "module",
+            Some("group"),
+            2,
+            true,
+            passing_factory(),
+        );
+
+        let (result, summary) = run_and_get_summary(runner).await?;
+        assert!(result.is_err(), "a real failure should still fail the run");
+        assert_eq!(summary.total_tests, 3);
+        assert_eq!(summary.passed_tests, 1);
+        assert_eq!(summary.failed_tests, 1);
+        assert_eq!(summary.quarantined_tests, 1);
+        assert_eq!(summary.skipped_tests, 0);
+
+        Ok(())
+    }
+
+    fn create_config_with_sla() -> Config {
+        Config {
+            projects: vec![Arc::new(ProjectConfig {
+                name: "default".into(),
+                sla: HashMap::from([("module::sla_slow".into(), Duration::from_millis(1))]),
+                ..Default::default()
+            })],
+            ..Default::default()
+        }
+    }
+
+    fn slow_factory() -> TestCaseFactory {
+        Arc::new(|| {
+            Box::pin(async {
+                tokio::time::sleep(Duration::from_millis(50)).await;
+                Ok(())
+            })
+        })
+    }
+
+    #[tokio::test]
+    #[serial_test::serial]
+    async fn runner_sla_violation_fails_test() -> eyre::Result<()> {
+        let mut rx = subscribe()?;
+        let mut runner = Runner::with_config(create_config_with_sla());
+        runner.add_test("sla_slow", "module", None, 0, false, slow_factory());
+
+        let result = runner.run(&[], &[], &[]).await;
+        assert!(result.is_err());
+
+        let mut violation_check = None;
+        let mut end = None;
+        while let Ok(event) = rx.try_recv() {
+            if event.test != "sla_slow" {
+                continue;
+            }
+            match event.body {
+                EventBody::Check(check) => violation_check = Some(check),
+                EventBody::End(test) => end = Some(test),
+                _ => {}
+            }
+        }
+
+        let check = violation_check.expect("should have received SLA violation Check");
+        assert!(!check.result);
+        assert!(check.expr.contains("exceeding the SLA"), "{}", check.expr);
+        let test = end.expect("should have received End event");
+        assert!(matches!(test.result, Err(Error::SlaViolated(_))));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[serial_test::serial]
+    async fn runner_disable_sla_skips_enforcement() -> eyre::Result<()> {
+        let _runner_rx = subscribe()?;
+        let mut runner = Runner::with_config(create_config_with_sla());
+        runner.disable_sla();
+        runner.add_test("sla_slow", "module", None, 0, false, slow_factory());
+
+        let result = runner.run(&[], &[], &[]).await;
+        assert!(result.is_ok());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[serial_test::serial]
+    async fn runner_sla_excludes_serial_group_wait() -> eyre::Result<()> {
+        let mut rx = subscribe()?;
+        // Each test sleeps 100ms, well under its SLA on its own, but the second one to
+        // acquire the serial guard waits ~100ms for the first one to finish.
+        let limit = Duration::from_millis(180);
+        let mut runner = Runner::with_config(Config {
+            projects: vec![Arc::new(ProjectConfig {
+                name: "default".into(),
+                sla: HashMap::from([
+                    ("module::sla_serial1".into(), limit),
+                    ("module::sla_serial2".into(), limit),
+                ]),
+                ..Default::default()
+            })],
+            ..Default::default()
+        });
+        let factory: TestCaseFactory = Arc::new(|| {
+            Box::pin(async {
+                tokio::time::sleep(Duration::from_millis(100)).await;
+                Ok(())
+            })
+        });
+        runner.add_test(
+            "sla_serial1",
+            "module",
+            Some("sla"),
+            0,
+            false,
+            factory.clone(),
+        );
+        runner.add_test("sla_serial2", "module", Some("sla"), 1, false, factory);
+
+        let result = runner.run(&[], &[], &[]).await;
+
+        let mut ends = Vec::new();
+        while let Ok(event) = rx.try_recv() {
+            if let EventBody::End(test) = event.body {
+                ends.push(test);
+            }
+        }
+        assert_eq!(ends.len(), 2);
+        for test in ends {
+            assert!(
+                !matches!(test.result, Err(Error::SlaViolated(_))),
+                "{} should not violate its SLA: {:?}",
+                test.info.name,
+                test.result
+            );
+        }
+        assert!(result.is_ok());
+
+        Ok(())
+    }
+
     // Verify that HTTP Call events are published to the channel regardless of
     // the capture_http mode (the HTTP client always publishes; the reporter
     // decides what to display).
@@ -2180,4 +2599,27 @@ mod test {
         runner.set_capture_http_mode(CaptureHttpMode::Off);
         assert_eq!(runner.options.capture_http, CaptureHttpMode::Off);
     }
+
+    #[test]
+    fn add_test_description_defaults_to_empty() {
+        let mut runner = Runner::new();
+        runner.add_test("undocumented", "module", None, 0, false, passing_factory());
+        runner.add_test_info(
+            TestInfo {
+                name: "documented".into(),
+                module: "module".into(),
+                line: 1,
+                description: "Checks something.".into(),
+                ..Default::default()
+            },
+            passing_factory(),
+        );
+
+        let descriptions = runner
+            .list()
+            .into_iter()
+            .map(|info| info.description.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(descriptions, ["", "Checks something."]);
+    }
 }
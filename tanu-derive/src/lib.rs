@@ -243,6 +243,35 @@ fn inspect_error_crate(sig: &Signature) -> ErrorCrate {
     }
 }
 
+/// Builds the test description from the `#[doc]` attributes of the test function.
+///
+/// Each doc line is trimmed of surrounding whitespace and lines are joined with `\n`.
+/// Returns an empty string when the function has no doc comment.
+fn extract_description(attrs: &[syn::Attribute]) -> String {
+    attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("doc"))
+        .filter_map(|attr| match &attr.meta {
+            syn::Meta::NameValue(syn::MetaNameValue {
+                value:
+                    Expr::Lit(ExprLit {
+                        lit: Lit::Str(s), ..
+                    }),
+                ..
+            }) => Some(s.value()),
+            _ => None,
+        })
+        .flat_map(|doc| {
+            doc.split('\n')
+                .map(|line| line.trim().to_string())
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+        .trim()
+        .to_string()
+}
+
 #[allow(dead_code)]
 /// Returns the name of the variant of the given expression.
 fn get_expr_variant_name(expr: &Expr) -> &'static str {
@@ -448,6 +477,7 @@ pub fn test(args: TokenStream, input: TokenStream) -> TokenStream {
     };
 
     let ordered = input_args.ordered;
+    let description = extract_description(&input_fn.attrs);
 
     // tanu internally relies on the `eyre` and `color-eyre` crates for error handling.
     // since `tanu::Runner` expects test functions to return an `eyre::Result`, the macro
@@ -471,6 +501,7 @@ pub fn test(args: TokenStream, input: TokenStream) -> TokenStream {
                     serial_group: #serial_group_tokens,
                     line: line!(),
                     ordered: #ordered,
+                    description: #description,
                     test_fn: || {
                         Box::pin(async move {
                             #func_name_inner(#args).await
@@ -491,6 +522,7 @@ pub fn test(args: TokenStream, input: TokenStream) -> TokenStream {
                     serial_group: #serial_group_tokens,
                     line: line!(),
                     ordered: #ordered,
+                    description: #description,
                     test_fn: || {
                         Box::pin(async move {
                             #func_name_inner(#args).await.map_err(|e| ::tanu::eyre::eyre!(Box::new(e)))
@@ -551,12 +583,15 @@ pub fn main(_args: TokenStream, input: TokenStream) -> TokenStream {
 
             // Use inventory to discover all registered tests
             for test in ::tanu::inventory::iter::<::tanu::TestRegistration> {
-                runner.add_test(
-                    test.name,
-                    test.module,
-                    test.serial_group,
-                    test.line,
-                    test.ordered,
+                runner.add_test_info(
+                    ::tanu::TestInfo {
+                        name: test.name.to_string(),
+                        module: test.module.to_string(),
+                        serial_group: test.serial_group.map(|s| s.to_string()),
+                        line: test.line,
+                        ordered: test.ordered,
+                        description: test.description.to_string(),
+                    },
                     std::sync::Arc::new(test.test_fn)
                 );
             }
@@ -586,6 +621,15 @@ mod test {
         super::inspect_error_crate(&sig)
     }
 
+    #[test_case("/// Checks the health endpoint.\nfn foo() {}" => "Checks the health endpoint."; "single line")]
+    #[test_case("///   First line.\n///\n///   Second line.\nfn foo() {}" => "First line.\n\nSecond line."; "multi line")]
+    #[test_case("/** Block\n    doc */\nfn foo() {}" => "Block\ndoc"; "block comment")]
+    #[test_case("#[allow(unused)]\nfn foo() {}" => ""; "no doc")]
+    fn extract_description(s: &str) -> String {
+        let item: syn::ItemFn = syn::parse_str(s).expect("failed to parse function");
+        super::extract_description(&item.attrs)
+    }
+
     #[test_case("Some(1)" => Some("1".into()); "Some with int")]
     #[test_case("Some(\"test\")" => Some("test".into()); "Some with string")]
     #[test_case("Some(true)" => Some("true".into()); "Some with boolean")]
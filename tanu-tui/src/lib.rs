@@ -138,6 +138,8 @@ struct Model {
     test_cases_list: TestListState,
     /// Contains the results of executed tests, including logs and the test itself
     test_results: Vec<TestResult>,
+    /// Metadata of all registered test cases
+    test_cases: Vec<TestInfo>,
     /// Maintains the state of the info pange, such as currently selected tab.
     info_state: InfoState,
     /// Holds the state of the logger pane, including any focus or visibility settings
@@ -157,6 +159,7 @@ impl Model {
             current_exec: None,
             test_cases_list: TestListState::new(&cfg.projects, &test_cases),
             test_results: vec![],
+            test_cases,
             info_state: InfoState::new(),
             logger_state: TuiWidgetState::new(),
             click: None,
@@ -528,7 +531,7 @@ fn view(model: &mut Model, frame: &mut Frame) {
         .select(model.info_state.selected_tab as usize)
         .selected_style(Style::default().fg(Color::Blue).bold());
 
-    let info = InfoWidget::new(model.test_results.clone());
+    let info = InfoWidget::new(model.test_results.clone(), model.test_cases.clone());
 
     let logo = BigText::builder()
         .pixel_size(PixelSize::Sextant)
@@ -18,7 +18,7 @@ use syntect::{
     highlighting::{Theme, ThemeSet},
     parsing::SyntaxSet,
 };
-use tanu_core::get_tanu_config;
+use tanu_core::{get_tanu_config, runner::TestInfo};
 use tracing::*;
 
 use crate::{widget::list::TestCaseSelector, TestResult};
@@ -87,6 +87,7 @@ pub type SelectedCall<'a> = &'a tanu_core::http::Log;
 
 pub struct InfoWidget {
     test_results: Vec<TestResult>,
+    test_cases: Vec<TestInfo>,
 }
 
 impl StatefulWidget for InfoWidget {
@@ -145,8 +146,19 @@ fn format_system_time(ts: SystemTime) -> String {
 }
 
 impl InfoWidget {
-    pub fn new(test_results: Vec<TestResult>) -> InfoWidget {
-        InfoWidget { test_results }
+    pub fn new(test_results: Vec<TestResult>, test_cases: Vec<TestInfo>) -> InfoWidget {
+        InfoWidget {
+            test_results,
+            test_cases,
+        }
+    }
+
+    /// Returns the metadata of the selected test, which is available before the test runs.
+    fn get_selected_test_info(&self, state: &InfoState) -> Option<&TestInfo> {
+        let test_name = state.selected_test.as_ref()?.test.as_ref()?;
+        self.test_cases
+            .iter()
+            .find(|info| info.full_name() == *test_name)
     }
 
     fn get_selected_test_result(
@@ -192,94 +204,104 @@ impl InfoWidget {
         const FIELD_PERCENTAGE: u16 = 30;
         const VALUE_PERCENTAGE: u16 = 70;
         let value_width = area.width * VALUE_PERCENTAGE / 100 - 3;
-        let Some((test_result, call)) = self.get_selected_test_result(state) else {
+        let (Some(selector), Some(info)) = (
+            state.selected_test.as_ref(),
+            self.get_selected_test_info(state),
+        ) else {
             return;
         };
 
         let colors = TableColors::new();
         let mut rows = vec![
-            wrap_row("Project Name", &test_result.project_name, value_width),
-            wrap_row("Test Name", &test_result.name, value_width),
+            wrap_row("Project Name", &selector.project, value_width),
+            wrap_row("Test Name", &info.name, value_width),
         ];
-        if let Some(test) = test_result.test.as_ref() {
-            rows.push(wrap_row(
-                "Test Started",
-                format_system_time(test.started_at),
-                value_width,
-            ));
-            rows.push(wrap_row(
-                "Test Ended",
-                format_system_time(test.ended_at),
-                value_width,
-            ));
-            rows.push(wrap_row(
-                "Test Duration",
-                format!("{:?}", test.request_time),
-                value_width,
-            ));
+        if !info.description.is_empty() {
+            rows.push(wrap_row("Description", &info.description, value_width));
         }
 
-        #[cfg(feature = "grpc")]
-        match call {
-            SelectedCall::Http(http_call) => {
-                rows.push(wrap_row("Request URL", &http_call.request.url, value_width));
-                rows.push(wrap_row("Method", &http_call.request.method, value_width));
-                rows.push(wrap_row(
-                    "Status",
-                    http_call.response.status.as_str(),
-                    value_width,
-                ));
+        // Call details are only available once the selected test has made a call
+        if let Some((test_result, call)) = self.get_selected_test_result(state) {
+            if let Some(test) = test_result.test.as_ref() {
                 rows.push(wrap_row(
-                    "Request Duration",
-                    format!("{:?}", http_call.response.duration_req),
+                    "Test Started",
+                    format_system_time(test.started_at),
                     value_width,
                 ));
-            }
-            SelectedCall::Grpc(grpc_call) => {
                 rows.push(wrap_row(
-                    "Method Path",
-                    &grpc_call.request.method,
+                    "Test Ended",
+                    format_system_time(test.ended_at),
                     value_width,
                 ));
                 rows.push(wrap_row(
-                    "Status Code",
-                    format!(
-                        "{:?} ({})",
-                        grpc_call.response.status_code, grpc_call.response.status_code as i32
-                    ),
+                    "Test Duration",
+                    format!("{:?}", test.request_time),
                     value_width,
                 ));
-                if !grpc_call.response.status_message.is_empty() {
+            }
+
+            #[cfg(feature = "grpc")]
+            match call {
+                SelectedCall::Http(http_call) => {
+                    rows.push(wrap_row("Request URL", &http_call.request.url, value_width));
+                    rows.push(wrap_row("Method", &http_call.request.method, value_width));
                     rows.push(wrap_row(
-                        "Status Message",
-                        &grpc_call.response.status_message,
+                        "Status",
+                        http_call.response.status.as_str(),
+                        value_width,
+                    ));
+                    rows.push(wrap_row(
+                        "Request Duration",
+                        format!("{:?}", http_call.response.duration_req),
                         value_width,
                     ));
                 }
+                SelectedCall::Grpc(grpc_call) => {
+                    rows.push(wrap_row(
+                        "Method Path",
+                        &grpc_call.request.method,
+                        value_width,
+                    ));
+                    rows.push(wrap_row(
+                        "Status Code",
+                        format!(
+                            "{:?} ({})",
+                            grpc_call.response.status_code, grpc_call.response.status_code as i32
+                        ),
+                        value_width,
+                    ));
+                    if !grpc_call.response.status_message.is_empty() {
+                        rows.push(wrap_row(
+                            "Status Message",
+                            &grpc_call.response.status_message,
+                            value_width,
+                        ));
+                    }
+                    rows.push(wrap_row(
+                        "Request Duration",
+                        format!("{:?}", grpc_call.response.duration),
+                        value_width,
+                    ));
+                }
+            }
+
+            #[cfg(not(feature = "grpc"))]
+            {
+                rows.push(wrap_row("Request URL", &call.request.url, value_width));
+                rows.push(wrap_row("Method", &call.request.method, value_width));
+                rows.push(wrap_row(
+                    "Status",
+                    call.response.status.as_str(),
+                    value_width,
+                ));
                 rows.push(wrap_row(
                     "Request Duration",
-                    format!("{:?}", grpc_call.response.duration),
+                    format!("{:?}", call.response.duration_req),
                     value_width,
                 ));
             }
         }
 
-        #[cfg(not(feature = "grpc"))]
-        {
-            rows.push(wrap_row("Request URL", &call.request.url, value_width));
-            rows.push(wrap_row("Method", &call.request.method, value_width));
-            rows.push(wrap_row(
-                "Status",
-                call.response.status.as_str(),
-                value_width,
-            ));
-            rows.push(wrap_row(
-                "Request Duration",
-                format!("{:?}", call.response.duration_req),
-                value_width,
-            ));
-        }
-
         // Apply alternating row colors
         let rows: Vec<Row> = rows
             .into_iter()
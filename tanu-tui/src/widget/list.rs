@@ -265,6 +265,7 @@ impl ExecutionStateController {
                     started_at: SystemTime::UNIX_EPOCH,
                     ended_at: SystemTime::UNIX_EPOCH,
                     request_time: std::time::Duration::from_secs(0),
+                    quarantined: false,
                 }),
                 ..Default::default()
             });
@@ -297,6 +298,7 @@ impl ExecutionStateController {
                         serial_group: None,
                         line: 0,
                         ordered: false,
+                        description: String::new(),
                     }),
                     worker_id: 0,
                     result: if ok {
@@ -309,6 +311,7 @@ impl ExecutionStateController {
                     started_at: SystemTime::UNIX_EPOCH,
                     ended_at: SystemTime::UNIX_EPOCH,
                     request_time: std::time::Duration::from_secs(0),
+                    quarantined: false,
                 }),
                 ..Default::default()
             });
@@ -820,6 +823,7 @@ mod test {
                 serial_group: None,
                 line: 0,
                 ordered: false,
+                description: String::new(),
             },
             TestInfo {
                 module: "bar".into(),
@@ -827,6 +831,7 @@ mod test {
                 serial_group: None,
                 line: 0,
                 ordered: false,
+                description: String::new(),
             },
         ];
 
@@ -904,6 +909,7 @@ mod test {
                 serial_group: None,
                 line: 0,
                 ordered: false,
+                description: String::new(),
             },
             TestInfo {
                 module: "bar".into(),
@@ -911,6 +917,7 @@ mod test {
                 serial_group: None,
                 line: 0,
                 ordered: false,
+                description: String::new(),
             },
         ];
 
@@ -1035,12 +1042,14 @@ mod test {
                     serial_group: None,
                     line: 0,
                     ordered: false,
+                    description: String::new(),
                 }),
                 worker_id: 0,
                 result: Ok(()),
                 started_at: SystemTime::UNIX_EPOCH,
                 ended_at: SystemTime::UNIX_EPOCH,
                 request_time: std::time::Duration::from_secs(0),
+                quarantined: false,
             }),
             ..Default::default()
         };
@@ -1058,12 +1067,14 @@ mod test {
                     serial_group: None,
                     line: 0,
                     ordered: false,
+                    description: String::new(),
                 }),
                 worker_id: 0,
                 result: Err(tanu_core::runner::Error::ErrorReturned("fail".into())),
                 started_at: SystemTime::UNIX_EPOCH,
                 ended_at: SystemTime::UNIX_EPOCH,
                 request_time: std::time::Duration::from_secs(0),
+                quarantined: false,
             }),
             ..Default::default()
         };
@@ -12,7 +12,7 @@ use std::{
 use tanu_core::CaptureHttpMode;
 use tanu_core::Filter;
 
-use crate::{get_tanu_config, ListReporter, ReporterType};
+use crate::{get_tanu_config, HarReporter, ListReporter, ReporterType};
 
 /// Define CLI color styles
 fn cli_styles() -> Styles {
@@ -88,6 +88,15 @@ fn build_cli<'a>(third_party_reporters: impl Iterator<Item = &'a String>) -> Cla
                     .long("fail-fast")
                     .help("Abort test execution after the first failure")
                     .action(ArgAction::SetTrue))
+                .arg(Arg::new("no-sla")
+                    .long("no-sla")
+                    .help("Do not fail tests that exceed the SLA configured in tanu.toml")
+                    .action(ArgAction::SetTrue))
+                .arg(Arg::new("export-har")
+                    .long("export-har")
+                    .value_name("FILE")
+                    .help("Export captured HTTP requests and responses to a HAR file")
+                    .value_parser(clap::value_parser!(std::path::PathBuf)))
         )
         .subcommand(
             ClapCommand::new("tui")
@@ -109,6 +118,10 @@ fn build_cli<'a>(third_party_reporters: impl Iterator<Item = &'a String>) -> Cla
         .subcommand(
             ClapCommand::new("ls")
                 .about("List test cases")
+                .arg(Arg::new("show-meta")
+                    .long("show-meta")
+                    .help("Show test metadata such as the description taken from the test's doc comment")
+                    .action(ArgAction::SetTrue))
         )
 }
 
@@ -313,6 +326,9 @@ impl App {
                 if fail_fast {
                     runner.set_fail_fast(true);
                 }
+                if test_matches.get_flag("no-sla") {
+                    runner.disable_sla();
+                }
                 runner.terminate_channel();
 
                 let mut reporters = std::mem::take(&mut self.third_party_reporters);
@@ -332,6 +348,9 @@ impl App {
                             .ok_or_else(|| eyre::eyre!("Unknown reporter: {reporter}"))?,
                     );
                 }
+                if let Some(path) = test_matches.get_one::<std::path::PathBuf>("export-har") {
+                    runner.add_reporter(HarReporter::new(path));
+                }
 
                 let color_env = std::env::var("CARGO_TERM_COLOR");
                 let color = match (color_command, color_env) {
@@ -375,9 +394,10 @@ impl App {
 
                 tanu_tui::run(runner, log_level, tanu_log_level).await
             }
-            Some(("ls", _)) => {
+            Some(("ls", ls_matches)) => {
                 use console::style;
 
+                let show_meta = ls_matches.get_flag("show-meta");
                 let filter = tanu_core::runner::TestIgnoreFilter::default();
                 let list = runner.list();
                 let test_case_by_module = list.iter().into_group_map_by(|test| test.module.clone());
@@ -402,6 +422,11 @@ impl App {
                                 style(&test_case.module).cyan(),
                                 style(&test_case.name).blue().bold()
                             ))?;
+                            if show_meta {
+                                for line in test_case.description.lines() {
+                                    term.write_line(&format!("      {}", style(line).dim()))?;
+                                }
+                            }
                         }
                     }
                 }
@@ -123,7 +123,7 @@ pub use tanu_core::{
     assertion,
     config::{get_config, get_tanu_config, CaptureHttpMode, Config, ProjectConfig},
     http, reporter,
-    reporter::{ListReporter, NullReporter, Reporter, ReporterType},
+    reporter::{HarReporter, ListReporter, NullReporter, Reporter, ReporterType},
     runner::{self, scope_current, Runner, TestInfo},
     {check, check_eq, check_ne, check_str_eq},
 };
@@ -147,6 +147,8 @@ pub struct TestRegistration {
     pub serial_group: Option<&'static str>,
     pub line: u32,
     pub ordered: bool,
+    /// Doc comment of the test function, or empty if it has none.
+    pub description: &'static str,
     pub test_fn: AsyncTestFn,
 }
 